@@ -0,0 +1,44 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![doc(
+    html_logo_url = "https://use.ink/img/crate-docs/logo.png",
+    html_favicon_url = "https://use.ink/crate-docs/favicon.png"
+)]
+#![feature(rustc_private)]
+
+extern crate rustc_hir;
+extern crate rustc_lint;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+mod lints;
+mod registry;
+
+use rustc_lint::LintStore;
+use rustc_session::Session;
+
+#[doc(hidden)]
+dylint_linting::dylint_library!();
+
+/// Registers every ink! lint in this crate with rustc's lint store.
+///
+/// This is the entry point the `dylint` driver looks up by name (`#[no_mangle]`) when it
+/// loads this crate as a dylint library.
+#[allow(clippy::no_mangle_with_rust_abi)]
+#[no_mangle]
+pub fn register_lints(sess: &Session, lint_store: &mut LintStore) {
+    dylint_linting::init_config(sess);
+    lints::register_lints(lint_store);
+}
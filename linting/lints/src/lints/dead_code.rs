@@ -0,0 +1,248 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{
+    HashSet,
+    VecDeque,
+};
+
+use utils::{
+    clippy,
+    contract_associated_item_def_ids,
+    entrypoints::find_entrypoints,
+    expand_unnamed_consts,
+    find_contract_impls,
+    find_contract_trait_impls,
+    find_storage_struct,
+};
+
+use rustc_hir::{
+    def::{
+        DefKind,
+        Res,
+    },
+    def_id::DefId,
+    intravisit::{
+        nested_filter,
+        walk_expr,
+        Visitor,
+    },
+    Expr,
+    ExprKind,
+    ItemId,
+};
+use rustc_lint::{
+    LateContext,
+    LateLintPass,
+};
+use rustc_middle::ty::TyCtxt;
+
+use crate::declare_ink_lint;
+
+declare_ink_lint! {
+    /// ### What it does
+    /// Checks for private methods defined in a contract's `impl` blocks that are never
+    /// reachable from any `#[ink(message)]`, `#[ink(constructor)]`, trait method, or `pub`
+    /// method.
+    ///
+    /// ### Why is this bad?
+    /// Code that can never run still gets compiled into the contract's Wasm blob, needlessly
+    /// inflating its size and the cost of deploying and instantiating it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// impl MyContract {
+    ///     #[ink(message)]
+    ///     pub fn flip(&mut self) {
+    ///         self.value = !self.value;
+    ///     }
+    ///
+    ///     // Never called by `flip`, a constructor, or anything else reachable from one:
+    ///     // dead code.
+    ///     fn unused_helper(&self) -> bool {
+    ///         self.value
+    ///     }
+    /// }
+    /// ```
+    pub DeadCode => DEAD_CODE, Warn, "private contract method is never reachable from an ink! entrypoint"
+}
+
+impl<'tcx> LateLintPass<'tcx> for DeadCode {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let item_ids: Vec<ItemId> = cx
+            .tcx
+            .hir()
+            .items()
+            .map(|item| item.item_id())
+            .collect();
+        let item_ids = expand_unnamed_consts(cx, &item_ids);
+
+        // Not a contract crate (e.g. a library without `#[ink(storage)]`): nothing to check.
+        if find_storage_struct(cx, &item_ids).is_none() {
+            return;
+        }
+
+        let mut impls = find_contract_impls(cx, &item_ids);
+        impls.extend(find_contract_trait_impls(cx, &item_ids));
+        // Associated consts aren't methods, and the lint is about unreachable methods (both
+        // by its doc comment and its diagnostic message below), so only consider `fn`s here.
+        let candidates: HashSet<DefId> = contract_associated_item_def_ids(cx, &impls)
+            .into_iter()
+            .filter(|def_id| cx.tcx.def_kind(*def_id) == DefKind::AssocFn)
+            .collect();
+        let entrypoint_def_ids: HashSet<DefId> = find_entrypoints(cx, &impls)
+            .iter()
+            .map(|entrypoint| entrypoint.def_id)
+            .collect();
+
+        let live = reachable_def_ids(cx, &candidates, &entrypoint_def_ids);
+
+        for def_id in &candidates {
+            if live.contains(def_id) {
+                continue;
+            }
+            if def_id.as_local().is_none() {
+                continue;
+            }
+            // Only private methods are ever reported: a `pub fn` may be called from outside
+            // this crate's call graph entirely (other contracts, trait consumers, `#[cfg(test)]`
+            // unit tests reaching in directly), so we can't conclude it's dead just because
+            // nothing in this crate happens to call it.
+            if cx.tcx.visibility(*def_id).is_public() {
+                continue;
+            }
+            clippy::diagnostics::span_lint(
+                cx,
+                DEAD_CODE,
+                cx.tcx.def_span(*def_id),
+                format!("method `{}` is never used", cx.tcx.item_name(*def_id)),
+            );
+        }
+    }
+}
+
+/// Is this method part of a trait `impl`?
+///
+/// Treated as live conservatively: the trait may be implemented for a reason external callers
+/// (or other code we don't walk, e.g. derive-generated code) rely on, so we don't want to flag
+/// it as dead just because nothing in this crate happens to call it directly.
+fn is_trait_impl_method(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    cx.tcx
+        .impl_of_method(def_id)
+        .is_some_and(|impl_id| cx.tcx.impl_trait_ref(impl_id).is_some())
+}
+
+/// Performs a worklist-based reachability pass over the given candidate methods, starting
+/// from the ink! entrypoints, trait impl methods, and `pub` methods among them, and following
+/// every call (including calls through function pointers and trait objects, which we
+/// approximate by treating any path expression resolving to a candidate as live) to its
+/// callee.
+///
+/// `pub` methods are seeded as roots, not just excluded from the final report: a private
+/// helper only ever called from a `pub` method would otherwise never be reached, even though
+/// it's live in the same sense an entrypoint's callees are.
+///
+/// This mirrors the approach taken by rustc's own dead-code analysis (`middle/dead.rs`):
+/// mark roots live, then propagate liveness along the call graph until it stops growing.
+fn reachable_def_ids(
+    cx: &LateContext<'_>,
+    candidates: &HashSet<DefId>,
+    entrypoint_def_ids: &HashSet<DefId>,
+) -> HashSet<DefId> {
+    let mut live = HashSet::new();
+    let mut worklist = VecDeque::new();
+
+    for &def_id in candidates {
+        let is_root = entrypoint_def_ids.contains(&def_id)
+            || is_trait_impl_method(cx, def_id)
+            || cx.tcx.visibility(def_id).is_public();
+        if is_root && live.insert(def_id) {
+            worklist.push_back(def_id);
+        }
+    }
+
+    while let Some(def_id) = worklist.pop_front() {
+        for callee in called_def_ids(cx.tcx, def_id) {
+            if candidates.contains(&callee) && live.insert(callee) {
+                worklist.push_back(callee);
+            }
+        }
+    }
+
+    live
+}
+
+/// Returns every `DefId` referenced from the body of `def_id`, whether called directly,
+/// called as a method, or merely referred to by path (e.g. taken as a function pointer or
+/// coerced to a trait object).
+fn called_def_ids(tcx: TyCtxt<'_>, def_id: DefId) -> Vec<DefId> {
+    let Some(local_def_id) = def_id.as_local() else {
+        return Vec::new();
+    };
+    let Some(body_id) = tcx.hir().maybe_body_owned_by(local_def_id) else {
+        return Vec::new();
+    };
+    let body = tcx.hir().body(body_id);
+
+    let mut collector = CalleeCollector {
+        tcx,
+        found: Vec::new(),
+    };
+    collector.visit_expr(body.value);
+    collector.found
+}
+
+struct CalleeCollector<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    found: Vec<DefId>,
+}
+
+impl<'tcx> Visitor<'tcx> for CalleeCollector<'tcx> {
+    // The default `NestedFilter` (`nested_filter::None`) doesn't descend into nested item-like
+    // things at all, which includes closure bodies. Without this, a helper only ever called
+    // from inside a closure (e.g. `self.items.iter().map(|i| self.transform(i))`, a common
+    // pattern over storage collections) would never show up as a callee and would be wrongly
+    // reported as dead.
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.tcx.hir()
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Path(ref qpath) => {
+                // Type-relative paths such as `Self::helper` are only resolved by typeck.
+                let owner = expr.hir_id.owner.def_id;
+                if let Res::Def(_, def_id) =
+                    self.tcx.typeck(owner).qpath_res(qpath, expr.hir_id)
+                {
+                    self.found.push(def_id);
+                }
+            }
+            ExprKind::MethodCall(..) => {
+                let owner = expr.hir_id.owner.def_id;
+                if let Some(def_id) = self
+                    .tcx
+                    .typeck(owner)
+                    .type_dependent_def_id(expr.hir_id)
+                {
+                    self.found.push(def_id);
+                }
+            }
+            _ => {}
+        }
+        walk_expr(self, expr);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Centralizes declaration and registration of ink!-specific lints.
+//!
+//! Follows the `declare_lint!` / `late_lint_methods!` registration pattern from rustc's own
+//! `builtin.rs`: [`declare_ink_lint!`] is the `declare_lint!` counterpart, producing a
+//! `LintPass` alongside the lint itself, and [`register_ink_lints`] is the
+//! `late_lint_methods!` counterpart, wiring every declared pass into the `LintStore` in one
+//! call. Without this, every lint module would repeat the same `declare_lint!` +
+//! `declare_lint_pass!` boilerplate and re-derive its own `LintStore` registration.
+
+use rustc_lint::LintStore;
+
+/// Declares an ink! lint and its `LintPass` together.
+///
+/// Expands to a `declare_tool_lint!` for `$lint`, namespaced under the `ink` tool (so it
+/// shows up, and can be configured, as `ink::<name>` — e.g. `ink::dead_code` — rather than
+/// colliding with a same-named rustc builtin lint such as `dead_code`), and a
+/// `declare_lint_pass!` for `$pass`, plus a `$pass::descriptor()` associated function that
+/// [`register_ink_lints`] uses to wire both into the `LintStore`.
+///
+/// # Developer Note
+///
+/// An earlier version of this macro also accepted a `future_incompatible: true` flag, stored
+/// on [`LintDescriptor`] but never actually read by [`register_ink_lints`] (rustc's own
+/// `@future_incompatible` clause on `declare_tool_lint!` needs a `FutureIncompatibleInfo`,
+/// which this never constructed). No lint here needs it yet, so it was dropped rather than
+/// kept as a non-functional stub; add it back, wired through to `declare_tool_lint!`'s
+/// `@future_incompatible` form, once a lint actually needs it.
+#[macro_export]
+macro_rules! declare_ink_lint {
+    (
+        $(#[$doc:meta])*
+        pub $pass:ident => $lint:ident, $level:ident, $desc:literal
+    ) => {
+        rustc_session::declare_tool_lint! {
+            $(#[$doc])*
+            pub ink::$lint,
+            $level,
+            $desc
+        }
+
+        rustc_session::declare_lint_pass!($pass => [$lint]);
+
+        impl $pass {
+            /// Registration metadata for this lint, consumed by
+            /// [`crate::registry::register_ink_lints`].
+            pub(crate) fn descriptor() -> $crate::registry::LintDescriptor {
+                $crate::registry::LintDescriptor {
+                    register: |lint_store| {
+                        lint_store.register_lints(&[$lint]);
+                        lint_store.register_late_pass(|_| Box::new($pass));
+                    },
+                }
+            }
+        }
+    };
+}
+
+/// Registration metadata for one ink! lint, produced by `$pass::descriptor()` (see
+/// [`declare_ink_lint!`]).
+pub(crate) struct LintDescriptor {
+    /// Registers the lint and its pass with a `LintStore`.
+    pub(crate) register: fn(&mut LintStore),
+}
+
+/// Registers every ink! lint in `lints` with the given `LintStore` in one call.
+///
+/// This is the single place the dylint driver's `register_lints` entry point needs to call
+/// into; individual lint modules never touch the `LintStore` directly.
+pub(crate) fn register_ink_lints(lint_store: &mut LintStore, lints: &[LintDescriptor]) {
+    for descriptor in lints {
+        (descriptor.register)(lint_store);
+    }
+}
@@ -0,0 +1,34 @@
+#![allow(unused)]
+
+#[ink::contract]
+mod dead_code {
+    #[ink(storage)]
+    pub struct DeadCode {
+        value: bool,
+    }
+
+    impl DeadCode {
+        #[ink(constructor)]
+        pub fn new(init_value: bool) -> Self {
+            Self { value: init_value }
+        }
+
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+
+        // Never called from a message, a constructor, or anything reachable from one: dead
+        // code, and should be flagged.
+        fn unused_helper(&self) -> bool {
+            !self.value
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+#![allow(unused)]
+
+#[ink::contract]
+mod dead_code_closure_call {
+    #[ink(storage)]
+    pub struct Store {
+        values: ink::storage::Mapping<u32, u32>,
+        len: u32,
+    }
+
+    impl Store {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                values: Default::default(),
+                len: 0,
+            }
+        }
+
+        #[ink(message)]
+        pub fn sum(&self) -> u32 {
+            (0..self.len).map(|key| self.double(key)).sum()
+        }
+
+        // Only ever called from inside the closure above. Must not be flagged as dead: the
+        // reachability walk has to descend into closure bodies to see this call.
+        fn double(&self, key: u32) -> u32 {
+            self.values.get(key).unwrap_or_default() * 2
+        }
+    }
+}
+
+fn main() {}
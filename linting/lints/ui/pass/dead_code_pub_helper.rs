@@ -0,0 +1,30 @@
+#![allow(unused)]
+
+#[ink::contract]
+mod dead_code_pub_helper {
+    #[ink(storage)]
+    pub struct PubHelper {
+        value: bool,
+    }
+
+    impl PubHelper {
+        #[ink(constructor)]
+        pub fn new(init_value: bool) -> Self {
+            Self { value: init_value }
+        }
+
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        // Not an ink! entrypoint and never called from this crate's call graph, but `pub`:
+        // kept that way so `#[cfg(test)]` unit tests (or other contracts/trait consumers) can
+        // call it directly. Must not be flagged as dead; only private methods are.
+        pub fn peek(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+#![allow(unused)]
+
+#[ink::contract]
+mod dead_code_split_impl {
+    #[ink(storage)]
+    pub struct SplitImpl {
+        value: bool,
+    }
+
+    // Constructors and messages are spread across two `impl` blocks, as real contracts
+    // routinely do. Both blocks are collected (see `utils::find_contract_impls`), and none of
+    // their methods may be flagged as dead code.
+    impl SplitImpl {
+        #[ink(constructor)]
+        pub fn new(init_value: bool) -> Self {
+            Self { value: init_value }
+        }
+    }
+
+    impl SplitImpl {
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message, payable, selector = 0x12345678)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+fn main() {}
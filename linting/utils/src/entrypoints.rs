@@ -0,0 +1,162 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enumerates a contract's `#[ink(message)]`/`#[ink(constructor)]` entrypoints along with
+//! their ink!-specific metadata.
+//!
+//! This is the shared foundation several ink! lints need (reentrancy checks,
+//! payable-without-`&mut self` checks, selector-collision checks, ...), so it lives here
+//! rather than having each lint re-walk the HIR from scratch.
+
+use rustc_ast::LitKind;
+use rustc_hir::{
+    def_id::DefId,
+    FnSig,
+    HirId,
+    ImplItemKind,
+    ItemId,
+    ItemKind,
+    Mutability,
+};
+use rustc_lint::LateContext;
+
+/// Which kind of ink! callable an [`Entrypoint`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntrypointKind {
+    /// An `#[ink(message)]`.
+    Message,
+    /// An `#[ink(constructor)]`.
+    Constructor,
+}
+
+/// An `#[ink(message)]` or `#[ink(constructor)]`, together with the metadata ink! code
+/// generation attaches to it.
+#[derive(Debug, Clone)]
+pub struct Entrypoint {
+    pub kind: EntrypointKind,
+    pub def_id: DefId,
+    pub hir_id: HirId,
+    /// The receiver mutability (`&self` vs `&mut self`). Always `None` for constructors,
+    /// which take no `self`.
+    pub mutability: Option<Mutability>,
+    /// Whether this entrypoint is annotated `#[ink(payable)]`.
+    pub payable: bool,
+    /// The 4-byte selector, if one was explicitly assigned with `#[ink(selector = ..)]`.
+    pub selector: Option<u32>,
+    /// Whether this entrypoint is annotated `#[ink(default)]`.
+    pub default: bool,
+}
+
+/// Returns every `#[ink(message)]`/`#[ink(constructor)]` defined across the given contract
+/// `impl` blocks (see [`crate::find_contract_impls`] and [`crate::find_contract_trait_impls`]
+/// for how to collect those).
+pub fn find_entrypoints(cx: &LateContext<'_>, impl_ids: &[ItemId]) -> Vec<Entrypoint> {
+    impl_ids
+        .iter()
+        .flat_map(|impl_id| entrypoints_in_impl(cx, *impl_id))
+        .collect()
+}
+
+fn entrypoints_in_impl(cx: &LateContext<'_>, impl_id: ItemId) -> Vec<Entrypoint> {
+    let item = cx.tcx.hir().item(impl_id);
+    let ItemKind::Impl(item_impl) = &item.kind else {
+        return Vec::new();
+    };
+    item_impl
+        .items
+        .iter()
+        .filter_map(|impl_item_ref| {
+            let impl_item = cx.tcx.hir().impl_item(impl_item_ref.id);
+            let ImplItemKind::Fn(sig, _) = &impl_item.kind else {
+                return None;
+            };
+            let (kind, payable, selector, default) = parse_ink_attr(cx, impl_item.hir_id())?;
+            Some(Entrypoint {
+                kind,
+                def_id: impl_item.owner_id.to_def_id(),
+                hir_id: impl_item.hir_id(),
+                mutability: receiver_mutability(sig),
+                payable,
+                selector,
+                default,
+            })
+        })
+        .collect()
+}
+
+/// Parses the `#[ink(..)]` attribute directly attached to `hir_id` (ink! code generation
+/// leaves the original attribute in place on the method, it doesn't get macro-expanded away)
+/// and, if it names this method a `message` or `constructor`, returns its kind together with
+/// the `payable`, `selector`, and `default` arguments found alongside it.
+///
+/// # Developer Note
+///
+/// An earlier version of this function looked for `#[cfg(not(feature =
+/// "__ink_dylint_Message"))]`/`"__ink_dylint_Constructor"` markers, mirroring the approach
+/// [`crate::find_storage_struct`] takes for the storage struct. Unlike the storage marker,
+/// those two have never actually been confirmed to exist in ink!'s code generation output, so
+/// that approach silently found nothing. Parsing the `#[ink(message)]`/`#[ink(constructor)]`
+/// attribute's own `MetaItem` tree is both simpler and grounded in an attribute we know is
+/// really there.
+fn parse_ink_attr(
+    cx: &LateContext<'_>,
+    hir_id: HirId,
+) -> Option<(EntrypointKind, bool, Option<u32>, bool)> {
+    let mut kind = None;
+    let mut payable = false;
+    let mut selector = None;
+    let mut default = false;
+
+    for attr in cx.tcx.hir().attrs(hir_id) {
+        let Some(meta) = attr.meta() else { continue };
+        if meta.name_or_empty().as_str() != "ink" {
+            continue;
+        }
+        let Some(items) = meta.meta_item_list() else {
+            continue;
+        };
+        for nested in &items {
+            let Some(item) = nested.meta_item() else {
+                continue;
+            };
+            match item.name_or_empty().as_str() {
+                "message" => kind = Some(EntrypointKind::Message),
+                "constructor" => kind = Some(EntrypointKind::Constructor),
+                "payable" => payable = true,
+                "default" => default = true,
+                "selector" => {
+                    if let Some(lit) = item.name_value_literal() {
+                        if let LitKind::Int(value, _) = lit.kind {
+                            selector = Some(value.get() as u32);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    kind.map(|kind| (kind, payable, selector, default))
+}
+
+/// Reads off the receiver mutability of a method's `FnSig`, or `None` if it has no `self`
+/// receiver at all (as is the case for constructors).
+fn receiver_mutability(sig: &FnSig<'_>) -> Option<Mutability> {
+    use rustc_hir::ImplicitSelfKind;
+    match sig.decl.implicit_self {
+        ImplicitSelfKind::RefMut | ImplicitSelfKind::Mut => Some(Mutability::Mut),
+        ImplicitSelfKind::RefImm | ImplicitSelfKind::Imm => Some(Mutability::Not),
+        _ => None,
+    }
+}
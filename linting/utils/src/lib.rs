@@ -30,28 +30,41 @@ extern crate rustc_session;
 extern crate rustc_span;
 extern crate rustc_type_ir;
 
+pub mod entrypoints;
+
 pub use parity_clippy_utils as clippy;
 
 use clippy::match_def_path;
 use if_chain::if_chain;
+use rustc_ast::{
+    MetaItem,
+    NestedMetaItem,
+};
 use rustc_hir::{
+    def_id::DefId,
     ExprKind,
     HirId,
     ItemId,
     ItemKind,
+    OwnerId,
     QPath,
     StmtKind,
     Ty,
     TyKind,
 };
 use rustc_lint::LateContext;
+use rustc_span::{
+    sym,
+    Symbol,
+};
 
-/// Returns `true` iff the ink storage attribute is defined for the given HIR
+/// Which generation of ink!'s code generation produced the `#[cfg(not(..))]` marker this
+/// crate looks for to identify the storage struct.
 ///
 /// # Developer Note
 ///
 /// In ink! 5.0.0 our code generation added the annotation
-/// `#[cfg(not(feature = "__ink_dylint_Storage"))] to contracts. This
+/// `#[cfg(not(feature = "__ink_dylint_Storage"))]` to contracts. This
 /// allowed dylint to identify the storage struct in a contract.
 ///
 /// Starting with Rust 1.81, `cargo` throws a warning for features that
@@ -65,13 +78,83 @@ use rustc_lint::LateContext;
 /// `#[cfg(not(target_vendor = "fortanix"))]`, as it seems unlikely that a
 /// contract will ever be compiled for this target.
 ///
-/// We have to continue checking for the `__ink_dylint_Storage` attribute
-/// here, as the linting will otherwise stop working for ink! 5.0.0 contracts.
-fn has_storage_attr(cx: &LateContext, hir: HirId) -> bool {
-    const INK_STORAGE_1: &str = "__ink_dylint_Storage";
-    const INK_STORAGE_2: &str = "fortanix";
-    let attrs = format!("{:?}", cx.tcx.hir().attrs(hir));
-    attrs.contains(INK_STORAGE_1) || attrs.contains(INK_STORAGE_2)
+/// We have to continue recognizing the `__ink_dylint_Storage` marker here, as the linting
+/// would otherwise stop working for contracts generated by ink! 5.0.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InkVersion {
+    /// ink! 5.0.0, which marks the storage struct with
+    /// `#[cfg(not(feature = "__ink_dylint_Storage"))]`.
+    V5_0_0,
+    /// Later ink! releases, which switched to
+    /// `#[cfg(not(target_vendor = "fortanix"))]`.
+    Fortanix,
+}
+
+/// Which of the two known `#[cfg(not(..))]` storage markers a HIR node carries, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InkMarker {
+    /// `#[cfg(not(feature = "__ink_dylint_Storage"))]`
+    DylintStorageFeature,
+    /// `#[cfg(not(target_vendor = "fortanix"))]`
+    Fortanix,
+}
+
+impl From<InkMarker> for InkVersion {
+    fn from(marker: InkMarker) -> Self {
+        match marker {
+            InkMarker::DylintStorageFeature => InkVersion::V5_0_0,
+            InkMarker::Fortanix => InkVersion::Fortanix,
+        }
+    }
+}
+
+/// Extracts `(key, value)` out of a `#[cfg(not(key = "value"))]` attribute's parsed
+/// `MetaItem`, or `None` if `meta` doesn't have that exact shape.
+fn cfg_not_single_kv(meta: &MetaItem) -> Option<(Symbol, Symbol)> {
+    let items = meta.meta_item_list()?;
+    let [NestedMetaItem::MetaItem(not_item)] = items.as_slice() else {
+        return None;
+    };
+    if !not_item.has_name(sym::not) {
+        return None;
+    }
+    let inner = not_item.meta_item_list()?;
+    let [NestedMetaItem::MetaItem(kv)] = inner.as_slice() else {
+        return None;
+    };
+    let value = kv.value_str()?;
+    Some((kv.name_or_empty(), value))
+}
+
+/// Structurally inspects the `#[cfg(..)]` attributes on `hir` and returns the ink! storage
+/// marker they encode, if any.
+///
+/// This walks the parsed `MetaItem` tree of each attribute rather than stringifying the whole
+/// attribute list with `{:?}` and searching it for a magic substring, which is brittle and
+/// breaks silently whenever the `Debug` output of `rustc_ast` changes across compiler
+/// versions.
+fn find_ink_marker(cx: &LateContext, hir: HirId) -> Option<InkMarker> {
+    cx.tcx.hir().attrs(hir).iter().find_map(|attr| {
+        let meta = attr.meta()?;
+        if !meta.has_name(sym::cfg) {
+            return None;
+        }
+        let (key, value) = cfg_not_single_kv(&meta)?;
+        match (key.as_str(), value.as_str()) {
+            ("feature", "__ink_dylint_Storage") => Some(InkMarker::DylintStorageFeature),
+            ("target_vendor", "fortanix") => Some(InkMarker::Fortanix),
+            _ => None,
+        }
+    })
+}
+
+/// Returns which [`InkVersion`] generated the storage marker on `item`, if any.
+///
+/// Lets lints branch on contract generation version instead of only checking whether a
+/// contract is recognized at all.
+pub fn detect_ink_version(cx: &LateContext, item: ItemId) -> Option<InkVersion> {
+    let hir_item = cx.tcx.hir().item(item);
+    find_ink_marker(cx, hir_item.hir_id()).map(InkVersion::from)
 }
 
 /// Returns `ItemId` of the structure annotated with `#[ink(storage)]`
@@ -81,7 +164,7 @@ pub fn find_storage_struct(cx: &LateContext, item_ids: &[ItemId]) -> Option<Item
         .find(|&item_id| {
             let item = cx.tcx.hir().item(*item_id);
             if_chain! {
-                if has_storage_attr(cx, item.hir_id());
+                if find_ink_marker(cx, item.hir_id()).is_some();
                 if let ItemKind::Struct(..) = item.kind;
                 then { true } else { false }
 
@@ -159,6 +242,13 @@ fn eq_hir_struct_tys(lhs: &Ty<'_>, rhs: &Ty<'_>) -> bool {
 }
 
 /// Finds an ID of the implementation of the contract struct containing user-defined code
+///
+/// # Developer Note
+///
+/// This only ever returns the first matching `impl` block. Contracts that split their
+/// `#[ink(message)]`/`#[ink(constructor)]` methods across several `impl Contract { .. }`
+/// sections will have their remaining methods silently ignored. Prefer
+/// [`find_contract_impls`] for lints that need to see the whole contract surface.
 pub fn find_contract_impl_id(
     cx: &LateContext<'_>,
     item_ids: Vec<ItemId>,
@@ -177,3 +267,90 @@ pub fn find_contract_impl_id(
         })
         .copied()
 }
+
+/// Resolves the `DefId` of the structure annotated with `#[ink(storage)]`
+fn find_contract_struct_def_id(
+    cx: &LateContext<'_>,
+    item_ids: &[ItemId],
+) -> Option<DefId> {
+    let storage_struct = find_storage_struct(cx, item_ids)?;
+    Some(cx.tcx.hir().item(storage_struct).owner_id.to_def_id())
+}
+
+/// Converts a `DefId` back into an `ItemId`, provided it refers to an item local to this
+/// crate (which is always the case for a contract's own `impl` blocks).
+fn local_item_id(def_id: DefId) -> Option<ItemId> {
+    def_id
+        .as_local()
+        .map(|def_id| ItemId { owner_id: OwnerId { def_id } })
+}
+
+/// Returns the `ItemId`s of every inherent `impl` block of the contract's storage struct.
+///
+/// # Developer Note
+///
+/// [`find_contract_impl_id`] locates the contract's `impl` block by comparing HIR `self_ty`
+/// paths syntactically, so it only ever finds one such block. Real contracts routinely split
+/// their `#[ink(message)]`/`#[ink(constructor)]` methods across several `impl Contract { .. }`
+/// sections, so that approach misses methods.
+///
+/// Instead, mirroring the approach rustc's own dead-code analysis (`middle/dead.rs`) takes, we
+/// resolve the storage struct to a `DefId` and ask `TyCtxt::inherent_impls` for every inherent
+/// impl of that type directly, rather than relying on syntactic equality.
+pub fn find_contract_impls(cx: &LateContext<'_>, item_ids: &[ItemId]) -> Vec<ItemId> {
+    let Some(def_id) = find_contract_struct_def_id(cx, item_ids) else {
+        return Vec::new();
+    };
+    cx.tcx
+        .inherent_impls(def_id)
+        .iter()
+        .filter_map(|impl_def_id| local_item_id(*impl_def_id))
+        .collect()
+}
+
+/// Returns the `ItemId`s of every `impl SomeTrait for Contract { .. }` block for the
+/// contract's storage struct, as a counterpart to [`find_contract_impls`].
+///
+/// Trait impls aren't covered by `TyCtxt::inherent_impls`, so we fall back to scanning the
+/// crate's items for `impl` blocks whose `self_ty` matches the storage struct and which do
+/// carry a trait reference.
+pub fn find_contract_trait_impls(
+    cx: &LateContext<'_>,
+    item_ids: &[ItemId],
+) -> Vec<ItemId> {
+    let Some(contract_struct_ty) = find_contract_ty_hir(cx, item_ids) else {
+        return Vec::new();
+    };
+    item_ids
+        .iter()
+        .filter(|item_id| {
+            if_chain! {
+                let item = cx.tcx.hir().item(**item_id);
+                if let ItemKind::Impl(item_impl) = &item.kind;
+                if item_impl.of_trait.is_some();
+                if eq_hir_struct_tys(contract_struct_ty, item_impl.self_ty);
+                then { true } else { false }
+            }
+        })
+        .copied()
+        .collect()
+}
+
+/// Returns the `DefId`s of every associated item (method, constant, or type) defined across the
+/// given contract `impl` blocks, by way of `TyCtxt::associated_item_def_ids`.
+///
+/// Intended to be called with the output of [`find_contract_impls`] and/or
+/// [`find_contract_trait_impls`] so that downstream lints can reason about the full set of
+/// methods a contract exposes, regardless of how many `impl` blocks they're spread across.
+pub fn contract_associated_item_def_ids(
+    cx: &LateContext<'_>,
+    impl_ids: &[ItemId],
+) -> Vec<DefId> {
+    impl_ids
+        .iter()
+        .flat_map(|impl_id| {
+            let def_id = cx.tcx.hir().item(*impl_id).owner_id.to_def_id();
+            cx.tcx.associated_item_def_ids(def_id).iter().copied()
+        })
+        .collect()
+}